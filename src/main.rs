@@ -14,36 +14,22 @@ use stardust_xr_fusion::{
 };
 use std::{path::PathBuf, sync::Arc};
 use tracing_subscriber::EnvFilter;
-use turntable::{Turntable, TurntableSettings};
+use turntable::{CarouselEntry, Turntable, TurntableSettings, TurntableState};
 
 #[derive(Parser)]
 pub struct Args {
-    file_path: PathBuf,
+    file_paths: Vec<PathBuf>,
 }
 
 struct Root {
     turntable: Turntable,
-    _model: Model,
+    _models: Vec<Arc<Model>>,
 }
 impl Root {
     async fn new(client: Arc<Client>, args: Args, radius: f32) -> Result<Self> {
-        let model = Model::create(
-            client.get_root(),
-            Transform::from_translation([0.0; 3]),
-            &ResourceID::new_direct(
-                args.file_path
-                    .canonicalize()
-                    .map_err(|_| NodeError::InvalidPath)?,
-            )?,
-        )?;
-        let model_bounds = model.get_relative_bounding_box(client.get_root()).await?;
-        dbg!(&model_bounds);
-        let max_model_dim = model_bounds
-            .size
-            .x
-            .max(model_bounds.size.y.max(model_bounds.size.z));
-        let mut scale = radius * 2.0 / max_model_dim;
-        scale = scale.min(1.0);
+        if args.file_paths.is_empty() {
+            bail!("expected at least one model file path");
+        }
         let turntable = Turntable::create(
             client.get_root(),
             Transform::identity(),
@@ -53,16 +39,67 @@ impl Root {
                 height: 0.03,
                 inner_radius: radius,
                 scroll_multiplier: 10.0_f32.to_radians(),
+                detent_count: (args.file_paths.len() > 1).then_some(args.file_paths.len() as u32),
+                detent_stiffness: 20.0,
+                friction: 1.5,
+                max_omega: Some(20.0),
+                idle_dwell: 10.0,
+                idle_ramp_time: 3.0,
+                idle_spin_speed: 15.0_f32.to_radians(),
+                fade_min_alpha: 0.1,
+                fade_distance: 0.3,
+                fade_idle_timeout: 5.0,
             },
         )?;
-        model.set_spatial_parent(turntable.content_parent())?;
-        let mut position = vec3(0.0, model_bounds.size.y * scale / 2.0, 0.0);
-        position -= Vec3::from(model_bounds.center) * scale * 0.5;
-        model.set_local_transform(Transform::from_translation_scale(position, [scale; 3]))?;
+
+        let mut models = Vec::with_capacity(args.file_paths.len());
+        let mut entries = Vec::with_capacity(args.file_paths.len());
+        for file_path in &args.file_paths {
+            let model = Model::create(
+                client.get_root(),
+                Transform::from_translation([0.0; 3]),
+                &ResourceID::new_direct(
+                    file_path.canonicalize().map_err(|_| NodeError::InvalidPath)?,
+                )?,
+            )?;
+            let model_bounds = model.get_relative_bounding_box(client.get_root()).await?;
+            dbg!(&model_bounds);
+            let max_model_dim = model_bounds
+                .size
+                .x
+                .max(model_bounds.size.y.max(model_bounds.size.z));
+            let mut scale = radius * 2.0 / max_model_dim;
+            scale = scale.min(1.0);
+            let mut position = vec3(0.0, model_bounds.size.y * scale / 2.0, 0.0);
+            position -= Vec3::from(model_bounds.center) * scale * 0.5;
+            model.set_local_transform(Transform::from_translation_scale(position, [scale; 3]))?;
+
+            let model = Arc::new(model);
+            let name = file_path
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_else(|| file_path.to_string_lossy().into_owned());
+            entries.push(CarouselEntry {
+                name,
+                model: model.clone(),
+            });
+            models.push(model);
+        }
+        let mut turntable = turntable;
+        turntable.set_entries(entries)?;
+        if let Some(saved) = client
+            .get_root()
+            .client_state()
+            .data
+            .as_ref()
+            .and_then(|data| serde_json::from_value::<TurntableState>(data.clone()).ok())
+        {
+            turntable.restore(saved);
+        }
         turntable.root().set_zoneable(true)?;
         Ok(Root {
             turntable,
-            _model: model,
+            _models: models,
         })
     }
 }
@@ -71,7 +108,9 @@ impl RootHandler for Root {
         self.turntable.update(info);
     }
     fn save_state(&mut self) -> MethodResult<ClientState> {
-        ClientState::from_root(self.turntable.root())
+        let mut state = ClientState::from_root(self.turntable.root())?;
+        state.data = Some(serde_json::to_value(self.turntable.save_state()).unwrap());
+        Ok(state)
     }
 }
 
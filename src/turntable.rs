@@ -1,7 +1,8 @@
 use glam::{Quat, Vec3};
 use map_range::MapRange;
+use serde::{Deserialize, Serialize};
 use stardust_xr_fusion::{
-    drawable::{Line, LinePoint, Lines, LinesAspect},
+    drawable::{Line, LinePoint, Lines, LinesAspect, Model},
     fields::{CylinderShape, Field, Shape},
     input::{InputData, InputDataType, InputHandler},
     node::NodeError,
@@ -10,11 +11,30 @@ use stardust_xr_fusion::{
     values::color::rgba_linear,
 };
 use stardust_xr_molecules::input_action::{InputQueue, InputQueueable, SimpleAction, SingleAction};
-use std::f32::{
-    consts::{FRAC_PI_2, TAU},
-    INFINITY,
+use std::{
+    f32::{
+        consts::{FRAC_PI_2, PI, TAU},
+        INFINITY,
+    },
+    sync::Arc,
 };
 
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct TurntableState {
+    pub rotation: f32,
+    pub omega: f32,
+}
+
+fn wrap_to_pi(angle: f32) -> f32 {
+    (angle + PI).rem_euclid(TAU) - PI
+}
+
+#[derive(Clone)]
+pub struct CarouselEntry {
+    pub name: String,
+    pub model: Arc<Model>,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct TurntableSettings {
     pub line_count: u32,
@@ -22,6 +42,16 @@ pub struct TurntableSettings {
     pub height: f32,
     pub inner_radius: f32,
     pub scroll_multiplier: f32,
+    pub detent_count: Option<u32>,
+    pub detent_stiffness: f32,
+    pub friction: f32,
+    pub max_omega: Option<f32>,
+    pub idle_dwell: f32,
+    pub idle_ramp_time: f32,
+    pub idle_spin_speed: f32,
+    pub fade_min_alpha: f32,
+    pub fade_distance: f32,
+    pub fade_idle_timeout: f32,
 }
 impl TurntableSettings {
     fn grip_lines(&self) -> Vec<Line> {
@@ -112,9 +142,13 @@ pub struct Turntable {
     input: InputQueue,
     pointer_hover_action: SimpleAction,
     touch_action: SingleAction,
-    angular_momentum: f32,
+    omega: f32, // rad/s, frame-rate independent
     prev_angle: Option<f32>,
     rotation: f32,
+    entries: Vec<CarouselEntry>,
+    _entry_slots: Vec<Spatial>,
+    detent_velocity: f32,
+    idle_time: f32,
 }
 impl Turntable {
     pub fn create(
@@ -152,7 +186,11 @@ impl Turntable {
             touch_action: Default::default(),
             prev_angle: None,
             rotation: 0.0,
-            angular_momentum: 0.0,
+            omega: 0.0,
+            entries: Vec::new(),
+            _entry_slots: Vec::new(),
+            detent_velocity: 0.0,
+            idle_time: 0.0,
         })
     }
 
@@ -163,6 +201,64 @@ impl Turntable {
         &self.content_parent
     }
 
+    pub fn set_entries(&mut self, entries: Vec<CarouselEntry>) -> Result<(), NodeError> {
+        let count = entries.len() as f32;
+        let radial_offset = self.settings.inner_radius * 0.5;
+        let mut slots = Vec::with_capacity(entries.len());
+        for (i, entry) in entries.iter().enumerate() {
+            let angle = (i as f32) / count * TAU; // evenly spaced around the ring
+            let (sin, cos) = angle.sin_cos();
+            let slot = Spatial::create(
+                &self.content_parent,
+                Transform::from_translation([cos * radial_offset, 0.0, sin * radial_offset]),
+                false,
+            )?;
+            entry.model.set_spatial_parent(&slot)?;
+            slots.push(slot);
+        }
+        self._entry_slots = slots;
+        self.entries = entries;
+        Ok(())
+    }
+
+    pub fn focused_index(&self) -> usize {
+        if self.entries.is_empty() {
+            return 0;
+        }
+        let step = TAU / self.entries.len() as f32;
+        let nearest = (self.rotation / step).round();
+        nearest.rem_euclid(self.entries.len() as f32) as usize
+    }
+
+    pub fn focused_name(&self) -> Option<&str> {
+        self.entries
+            .get(self.focused_index())
+            .map(|e| e.name.as_str())
+    }
+
+    pub fn save_state(&self) -> TurntableState {
+        TurntableState {
+            rotation: self.rotation,
+            omega: self.omega,
+        }
+    }
+
+    pub fn restore(&mut self, state: TurntableState) {
+        self.omega = state.omega;
+        self.rotate(state.rotation - self.rotation);
+    }
+
+    // combines proximity and recent interaction into one alpha in [fade_min_alpha, 1.0]
+    fn fade_alpha(&self) -> f32 {
+        let proximity = interact_proximity(&self.input, Vec3::ZERO);
+        let proximity_factor = (1.0 - proximity / self.settings.fade_distance).clamp(0.0, 1.0);
+        let recent_factor =
+            (1.0 - self.idle_time / self.settings.fade_idle_timeout.max(f32::EPSILON))
+                .clamp(0.0, 1.0);
+        let fade_factor = proximity_factor.max(recent_factor);
+        self.settings.fade_min_alpha + (1.0 - self.settings.fade_min_alpha) * fade_factor
+    }
+
     #[inline]
     fn scroll(&self) -> f32 {
         self.pointer_hover_action
@@ -188,8 +284,6 @@ impl Turntable {
     }
 
     pub fn update(&mut self, info: FrameInfo) {
-        self.angular_momentum *= 0.98;
-
         self.pointer_hover_action
             .update(&self.input, &|input| match &input.input {
                 InputDataType::Pointer(_) => input.distance < 0.0,
@@ -210,7 +304,15 @@ impl Turntable {
             },
         );
 
-        self.rotate(-self.scroll() * self.settings.scroll_multiplier);
+        let scroll = self.scroll();
+        self.rotate(-scroll * self.settings.scroll_multiplier);
+
+        // any real input resets the idle clock and hands control straight back
+        if self.touch_action.actor_acting() || scroll.abs() > f32::EPSILON {
+            self.idle_time = 0.0;
+        } else {
+            self.idle_time += info.delta;
+        }
 
         // if touching
         if let Some(angle) = self
@@ -222,7 +324,7 @@ impl Turntable {
         {
             if let Some(prev_angle) = self.prev_angle {
                 let delta = prev_angle - angle;
-                self.angular_momentum = delta * info.delta;
+                self.omega = delta / info.delta;
                 self.rotate(delta);
             }
             self.prev_angle.replace(angle);
@@ -231,9 +333,36 @@ impl Turntable {
             self.prev_angle.take();
         }
         if !self.touch_action.actor_acting() {
-            self.rotate(self.angular_momentum / info.delta);
+            self.omega *= (-self.settings.friction * info.delta).exp();
+            if let Some(max_omega) = self.settings.max_omega {
+                self.omega = self.omega.clamp(-max_omega, max_omega);
+            }
+            self.rotate(self.omega * info.delta);
+
+            // showcase spin after a dwell with no input
+            let idle_spinning =
+                self.settings.idle_spin_speed != 0.0 && self.idle_time >= self.settings.idle_dwell;
+            if idle_spinning {
+                let ramp = ((self.idle_time - self.settings.idle_dwell)
+                    / self.settings.idle_ramp_time.max(f32::EPSILON))
+                .clamp(0.0, 1.0);
+                let eased = ramp * ramp * (3.0 - 2.0 * ramp);
+                self.rotate(self.settings.idle_spin_speed * eased * info.delta);
+                self.detent_velocity = 0.0;
+            } else if let Some(detent_count) = self.settings.detent_count.filter(|&c| c > 0) {
+                // critically-damped spring toward the nearest detent
+                let step = TAU / detent_count as f32;
+                let target = (self.rotation / step).round() * step;
+                let displacement = wrap_to_pi(target - self.rotation);
+                let k = self.settings.detent_stiffness;
+                let accel = k * displacement - 2.0 * k.sqrt() * self.detent_velocity;
+                self.detent_velocity += accel * info.delta;
+                self.rotate(self.detent_velocity * info.delta);
+            }
         }
 
+        let alpha = self.fade_alpha();
+
         // update grip color
         for line in &mut self.grip_lines {
             for point in &mut line.points {
@@ -243,7 +372,7 @@ impl Turntable {
                 )
                 .map_range(0.05..0.0, 1.0..0.0)
                 .clamp(0.0, 1.0);
-                point.color = rgba_linear!(lerp, lerp, lerp, 1.0);
+                point.color = rgba_linear!(lerp, lerp, lerp, alpha);
             }
         }
         self.grip.set_lines(&self.grip_lines).unwrap();